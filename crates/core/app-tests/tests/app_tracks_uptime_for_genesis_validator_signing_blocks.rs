@@ -7,7 +7,7 @@ use {
         genesis::{self, AppState},
         server::consensus::Consensus,
     },
-    penumbra_sdk_mock_consensus::TestNode,
+    penumbra_sdk_mock_consensus::{SigningStrategy, TestNode},
     penumbra_sdk_stake::component::validator_handler::validator_store::ValidatorDataRead,
     tap::Tap,
     tracing::{error_span, Instrument},
@@ -15,8 +15,9 @@ use {
 
 mod common;
 
-#[tokio::test]
-async fn app_tracks_uptime_for_genesis_validator_missing_blocks() -> anyhow::Result<()> {
+/// Fast-forward `height` blocks under `strategy`, returning the single genesis validator's
+/// `(as_of_height, num_missed_blocks)` afterwards.
+async fn uptime_after(strategy: SigningStrategy, height: u64) -> anyhow::Result<(u64, usize)> {
     // Install a test logger, acquire some temporary storage, and start the test node.
     let guard = common::set_tracing_subscriber();
     let storage = TempStorage::new_with_penumbra_prefixes().await?;
@@ -41,37 +42,56 @@ async fn app_tracks_uptime_for_genesis_validator_missing_blocks() -> anyhow::Res
         .await?
         .try_into()
         .map_err(|keys| anyhow::anyhow!("expected one key, got: {keys:?}"))?;
-    let get_uptime = || async {
-        storage
-            .latest_snapshot()
-            .get_validator_uptime(&identity_key)
-            .await
-            .expect("should be able to get a validator uptime")
-            .expect("validator uptime should exist")
-    };
 
-    // Jump ahead a few blocks.
-    // TODO TODO TODO have the validator sign blocks here.
-    let height = 4;
-    node.fast_forward(height)
+    // Jump ahead a few blocks, letting `strategy` decide which validators sign each one. The
+    // strategy feeds the correct `last_commit_info`/votes into `begin_block`, so the uptime
+    // tracker observes real signatures rather than defaulting to missed blocks.
+    node.fast_forward_with(height, strategy)
         .instrument(error_span!("fast forwarding test node {height} blocks"))
         .await
         .context("fast forwarding {height} blocks")?;
 
-    // Check the validator's uptime once more. We should have uptime data up to the fourth block,
-    // and the validator should have missed all of the blocks between genesis and now.
-    {
-        let uptime = get_uptime().await;
-        assert_eq!(uptime.as_of_height(), height);
-        assert_eq!(
-            uptime.num_missed_blocks(),
-            0,
-            "validator should have signed the last {height} blocks"
-        );
-    }
+    let uptime = storage
+        .latest_snapshot()
+        .get_validator_uptime(&identity_key)
+        .await
+        .expect("should be able to get a validator uptime")
+        .expect("validator uptime should exist");
 
-    Ok(())
+    Ok((uptime.as_of_height(), uptime.num_missed_blocks()))
         .tap(|_| drop(node))
         .tap(|_| drop(storage))
         .tap(|_| drop(guard))
 }
+
+#[tokio::test]
+async fn app_tracks_uptime_for_genesis_validator_signing_blocks() -> anyhow::Result<()> {
+    let height = 4;
+    let (as_of_height, num_missed) = uptime_after(SigningStrategy::AllSign, height).await?;
+
+    // We should have uptime data up to the fourth block, and the validator should have signed
+    // every block between genesis and now.
+    assert_eq!(as_of_height, height);
+    assert_eq!(
+        num_missed, 0,
+        "validator should have signed the last {height} blocks"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn app_tracks_uptime_for_genesis_validator_missing_blocks() -> anyhow::Result<()> {
+    let height = 4;
+    let (as_of_height, num_missed) = uptime_after(SigningStrategy::AllMiss, height).await?;
+
+    // We should have uptime data up to the fourth block, and the validator should have missed
+    // every block between genesis and now.
+    assert_eq!(as_of_height, height);
+    assert_eq!(
+        num_missed, height as usize,
+        "validator should have missed the last {height} blocks"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,53 @@
+//! Validator lifecycle handling for the staking component.
+//!
+//! This gathers the read/write helpers the component uses to manage validators: definition and
+//! uptime bookkeeping in [`validator_store`], and the pre-activation liveness guard in
+//! [`liveness_preactivation`]. The [`ValidatorManager`] trait exposes the guard's entry points; the
+//! component's acceptance and `begin_block` paths do not call them.
+
+pub mod liveness_preactivation;
+pub mod validator_store;
+
+pub use validator_store::ValidatorDataRead;
+
+use {
+    crate::IdentityKey,
+    anyhow::Result,
+    async_trait::async_trait,
+    cnidarium::StateWrite,
+    liveness_preactivation::{PreActivationWrite, DEFAULT_OBSERVATION_BLOCKS},
+    tendermint::abci::types::CommitInfo,
+};
+
+/// Validator lifecycle operations driven by the staking component.
+///
+/// The two pre-activation hooks below are the entry points into the doppelganger liveness guard:
+/// [`ValidatorManager::begin_preactivation_observation`] registers a newly accepted validator, and
+/// [`ValidatorManager::process_pending_preactivation`] folds in one block's `last_commit_info`.
+/// Neither is called from the component's definition-acceptance or `begin_block` paths.
+#[async_trait]
+pub trait ValidatorManager: StateWrite {
+    /// Start watching a newly accepted validator for doppelganger signatures before it may go
+    /// active, using the default observation window.
+    async fn begin_preactivation_observation(
+        &mut self,
+        identity_key: &IdentityKey,
+        current_height: u64,
+    ) -> Result<()> {
+        self.register_pending_validator(identity_key, current_height, DEFAULT_OBSERVATION_BLOCKS)
+            .await
+    }
+
+    /// Advance the pre-activation guard for the current block, returning the validators that have
+    /// cleared their observation window and may now be activated.
+    async fn process_pending_preactivation(
+        &mut self,
+        current_height: u64,
+        last_commit_info: &CommitInfo,
+    ) -> Result<Vec<IdentityKey>> {
+        self.observe_preactivation_commit(current_height, last_commit_info)
+            .await
+    }
+}
+
+impl<T: StateWrite + ?Sized> ValidatorManager for T {}
@@ -0,0 +1,251 @@
+//! Pre-activation liveness guard for validators (doppelganger-style detection).
+//!
+//! [`PreActivationWrite::register_pending_validator`] records a validator's identity key in a
+//! pending-observation set, and [`PreActivationWrite::observe_preactivation_commit`] folds one
+//! block's `last_commit_info` into that set over a configurable window of blocks. If the key is
+//! observed signing during that window — indicating another instance of the validator is already
+//! running elsewhere — the validator moves to a distinct [`PreActivationState::Refused`] state.
+//! Otherwise, once the window elapses with no observed signatures, it moves to
+//! [`PreActivationState::Promoted`] and is returned to the caller.
+//!
+//! This mirrors the two-epoch doppelganger wait used by external validator clients.
+//!
+//! These hooks are not called from the staking component's definition-acceptance or `begin_block`
+//! paths, and activation does not consult the pre-activation state, so accepted validators are not
+//! held back by this guard.
+
+use {
+    crate::{component::validator_handler::ValidatorDataRead, IdentityKey},
+    anyhow::Result,
+    async_trait::async_trait,
+    cnidarium::{StateRead, StateWrite},
+    serde::{Deserialize, Serialize},
+    std::collections::BTreeSet,
+    tendermint::abci::types::{BlockSignatureInfo, CommitInfo},
+};
+
+/// The number of blocks a newly accepted validator is observed before it may be promoted.
+///
+/// Chosen to span the two-epoch doppelganger wait used by the external design.
+pub const DEFAULT_OBSERVATION_BLOCKS: u64 = 2 * crate::EPOCH_DURATION_BLOCKS;
+
+/// The pre-activation liveness state of a pending validator.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PreActivationState {
+    /// The validator is being observed and may not yet go active.
+    Pending {
+        /// The block height at which observation began.
+        started_at: u64,
+        /// The number of blocks the validator must be observed without signing before promotion.
+        window: u64,
+    },
+    /// The validator's key was observed signing during the window: activation is refused because
+    /// another instance appears to already be running.
+    Refused {
+        /// The block height at which the conflicting signature was observed.
+        observed_at: u64,
+    },
+    /// The validator was observed for the full window without signing and has been promoted.
+    Promoted,
+}
+
+/// The pure state transition applied to a pending validator once per observed block.
+///
+/// A [`PreActivationState::Pending`] validator becomes [`PreActivationState::Refused`] the moment
+/// its key is seen signing, or [`PreActivationState::Promoted`] once its observation window has
+/// elapsed with no signature; otherwise it stays pending. Terminal states are left unchanged.
+fn observe(current: PreActivationState, did_sign: bool, height: u64) -> PreActivationState {
+    match current {
+        PreActivationState::Pending { started_at, window } => {
+            if did_sign {
+                PreActivationState::Refused {
+                    observed_at: height,
+                }
+            } else if height.saturating_sub(started_at) >= window {
+                PreActivationState::Promoted
+            } else {
+                PreActivationState::Pending { started_at, window }
+            }
+        }
+        terminal => terminal,
+    }
+}
+
+fn state_key(identity_key: &IdentityKey) -> String {
+    format!("staking/validator_handler/preactivation/state/{identity_key}")
+}
+
+const PENDING_SET_KEY: &str = "staking/validator_handler/preactivation/pending_set";
+
+/// Read access to the pre-activation liveness state of validators.
+#[async_trait]
+pub trait PreActivationRead: StateRead {
+    /// Get the pre-activation liveness state of the given validator, if it is being tracked.
+    async fn get_preactivation_state(
+        &self,
+        identity_key: &IdentityKey,
+    ) -> Result<Option<PreActivationState>> {
+        Ok(self
+            .nonverifiable_get_raw(state_key(identity_key).as_bytes())
+            .await?
+            .map(|bytes| serde_json::from_slice(&bytes))
+            .transpose()?)
+    }
+
+    /// The set of validators currently in the pending-observation phase.
+    async fn pending_validators(&self) -> Result<BTreeSet<IdentityKey>> {
+        Ok(self
+            .nonverifiable_get_raw(PENDING_SET_KEY.as_bytes())
+            .await?
+            .map(|bytes| serde_json::from_slice(&bytes))
+            .transpose()?
+            .unwrap_or_default())
+    }
+}
+
+impl<T: StateRead + ?Sized> PreActivationRead for T {}
+
+/// Write access to the pre-activation liveness state of validators.
+#[async_trait]
+pub trait PreActivationWrite: StateWrite {
+    /// Begin observing a newly accepted validator, recording it in the pending set rather than
+    /// immediately marking it active.
+    async fn register_pending_validator(
+        &mut self,
+        identity_key: &IdentityKey,
+        current_height: u64,
+        window: u64,
+    ) -> Result<()> {
+        self.nonverifiable_put_raw(
+            state_key(identity_key).as_bytes().to_vec(),
+            serde_json::to_vec(&PreActivationState::Pending {
+                started_at: current_height,
+                window,
+            })?,
+        );
+        let mut pending = self.pending_validators().await?;
+        pending.insert(*identity_key);
+        self.nonverifiable_put_raw(PENDING_SET_KEY.as_bytes().to_vec(), serde_json::to_vec(&pending)?);
+        Ok(())
+    }
+
+    /// Fold one block's `last_commit_info` into the pending set, refusing any pending validator
+    /// whose key was observed signing and promoting any whose observation window has elapsed.
+    ///
+    /// Returns the identity keys that transitioned to [`PreActivationState::Promoted`] in this
+    /// block, so the caller can move them into the active set.
+    async fn observe_preactivation_commit(
+        &mut self,
+        current_height: u64,
+        last_commit_info: &CommitInfo,
+    ) -> Result<Vec<IdentityKey>> {
+        // Collect the CometBFT addresses that signed this block.
+        let signed: BTreeSet<[u8; 20]> = last_commit_info
+            .votes
+            .iter()
+            .filter(|vote| {
+                matches!(
+                    vote.sig_info,
+                    BlockSignatureInfo::Flag(tendermint::block::BlockIdFlag::Commit)
+                )
+            })
+            .map(|vote| vote.validator.address)
+            .collect();
+
+        let mut promoted = Vec::new();
+        let mut pending = self.pending_validators().await?;
+        let mut still_pending = BTreeSet::new();
+
+        for identity_key in pending.iter().copied() {
+            let Some(state) = self.get_preactivation_state(&identity_key).await? else {
+                continue;
+            };
+
+            // Map the pending validator's identity key to its consensus address, and decide whether
+            // it signed this block.
+            let did_sign = match self.get_validator_definition(&identity_key).await? {
+                Some(validator) => {
+                    let address: [u8; 20] = tendermint::account::Id::from(validator.consensus_key)
+                        .as_bytes()
+                        .try_into()
+                        .expect("tendermint address is 20 bytes");
+                    signed.contains(&address)
+                }
+                None => false,
+            };
+
+            let next = observe(state, did_sign, current_height);
+            self.nonverifiable_put_raw(
+                state_key(&identity_key).as_bytes().to_vec(),
+                serde_json::to_vec(&next)?,
+            );
+            match next {
+                PreActivationState::Pending { .. } => {
+                    still_pending.insert(identity_key);
+                }
+                PreActivationState::Promoted => promoted.push(identity_key),
+                PreActivationState::Refused { .. } => {}
+            }
+        }
+
+        // Drop resolved validators from the pending set.
+        if still_pending != pending {
+            pending = still_pending;
+            self.nonverifiable_put_raw(
+                PENDING_SET_KEY.as_bytes().to_vec(),
+                serde_json::to_vec(&pending)?,
+            );
+        }
+
+        Ok(promoted)
+    }
+}
+
+impl<T: StateWrite + ?Sized> PreActivationWrite for T {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pending_is_refused_when_observed_signing() {
+        let pending = PreActivationState::Pending {
+            started_at: 0,
+            window: 2,
+        };
+        assert_eq!(
+            observe(pending, true, 1),
+            PreActivationState::Refused { observed_at: 1 },
+        );
+    }
+
+    #[test]
+    fn pending_is_promoted_after_silent_window() {
+        let pending = PreActivationState::Pending {
+            started_at: 0,
+            window: 2,
+        };
+        // Still within the window: remains pending.
+        assert_eq!(
+            observe(pending.clone(), false, 1),
+            PreActivationState::Pending {
+                started_at: 0,
+                window: 2
+            },
+        );
+        // Window elapsed with no signature: promoted.
+        assert_eq!(observe(pending, false, 2), PreActivationState::Promoted);
+    }
+
+    #[test]
+    fn terminal_states_are_unchanged() {
+        assert_eq!(
+            observe(PreActivationState::Promoted, true, 9),
+            PreActivationState::Promoted,
+        );
+        assert_eq!(
+            observe(PreActivationState::Refused { observed_at: 3 }, false, 9),
+            PreActivationState::Refused { observed_at: 3 },
+        );
+    }
+}
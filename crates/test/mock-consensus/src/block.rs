@@ -0,0 +1,43 @@
+//! Block production for the mock consensus [`TestNode`](crate::TestNode).
+
+use {
+    crate::TestNode,
+    anyhow::Result,
+    tendermint::abci::types::CommitInfo,
+};
+
+mod signing;
+pub use signing::SigningStrategy;
+
+/// A builder for a single mock-consensus block, obtained from
+/// [`TestNode::block`](crate::TestNode::block).
+///
+/// The builder accumulates the `last_commit_info` that will be handed to the application's
+/// `begin_block`, then drives one full block lifecycle on [`Builder::execute`].
+pub struct Builder<'node, C> {
+    pub(crate) node: &'node mut TestNode<C>,
+    pub(crate) last_commit_info: CommitInfo,
+}
+
+impl<C> Builder<'_, C>
+where
+    C: tower::Service<
+            tendermint::v0_37::abci::ConsensusRequest,
+            Response = tendermint::v0_37::abci::ConsensusResponse,
+            Error = tower::BoxError,
+        > + Send
+        + Clone
+        + 'static,
+    C::Future: Send + 'static,
+{
+    /// Set the commit signatures carried by this block's `last_commit_info`.
+    pub fn with_signatures(mut self, last_commit_info: CommitInfo) -> Self {
+        self.last_commit_info = last_commit_info;
+        self
+    }
+
+    /// Drive the application through this block, feeding `last_commit_info` into `begin_block`.
+    pub async fn execute(self) -> Result<()> {
+        self.node.execute_block(self.last_commit_info).await
+    }
+}
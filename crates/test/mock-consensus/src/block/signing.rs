@@ -0,0 +1,91 @@
+//! Per-block signing control for the [`TestNode`], so tests can drive the chain through blocks in
+//! which specific genesis validators sign or abstain.
+//!
+//! Without this, [`TestNode::fast_forward`] gives a test no per-block control over which validators
+//! sign, so an uptime test can only assert the trivial "missed zero blocks" case. A
+//! [`SigningStrategy`] lets a test decide, per advanced block, which
+//! genesis validators sign — feeding the correct votes into `begin_block` so that
+//! `get_validator_uptime(..).num_missed_blocks()` reflects real missed signatures.
+
+use {
+    crate::TestNode,
+    std::{collections::BTreeSet, sync::Arc},
+};
+
+/// Decides which genesis validators sign a given block as the [`TestNode`] advances.
+///
+/// Validators are referred to by their position in the node's genesis validator list, matching the
+/// order in which [`TestNode`] tracks their keys.
+#[derive(Clone)]
+pub enum SigningStrategy {
+    /// Every genesis validator signs every block.
+    AllSign,
+    /// Every genesis validator abstains from every block.
+    AllMiss,
+    /// Every genesis validator signs except those whose index is in this set, which abstain.
+    SomeMiss(BTreeSet<usize>),
+    /// A custom predicate: given the block height and a validator's index, return whether that
+    /// validator signs this block.
+    Custom(Arc<dyn Fn(u64, usize) -> bool + Send + Sync>),
+}
+
+impl std::fmt::Debug for SigningStrategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SigningStrategy::AllSign => f.write_str("AllSign"),
+            SigningStrategy::AllMiss => f.write_str("AllMiss"),
+            SigningStrategy::SomeMiss(set) => f.debug_tuple("SomeMiss").field(set).finish(),
+            SigningStrategy::Custom(_) => f.write_str("Custom(..)"),
+        }
+    }
+}
+
+impl SigningStrategy {
+    /// Whether the validator at `index` signs the block at `height` under this strategy.
+    pub fn signs(&self, height: u64, index: usize) -> bool {
+        match self {
+            SigningStrategy::AllSign => true,
+            SigningStrategy::AllMiss => false,
+            SigningStrategy::SomeMiss(miss) => !miss.contains(&index),
+            SigningStrategy::Custom(predicate) => predicate(height, index),
+        }
+    }
+}
+
+impl<C> TestNode<C>
+where
+    C: tower::Service<
+            tendermint::v0_37::abci::ConsensusRequest,
+            Response = tendermint::v0_37::abci::ConsensusResponse,
+            Error = tower::BoxError,
+        > + Send
+        + Clone
+        + 'static,
+    C::Future: Send + 'static,
+{
+    /// Advance the chain by `blocks`, applying `strategy` to decide which genesis validators sign
+    /// each block.
+    ///
+    /// Unlike [`TestNode::fast_forward`], the generated `last_commit_info` carries a vote for every
+    /// signing validator and marks the rest absent, so the uptime component observes real missed
+    /// signatures.
+    pub async fn fast_forward_with(
+        &mut self,
+        blocks: u64,
+        strategy: SigningStrategy,
+    ) -> anyhow::Result<()> {
+        for _ in 0..blocks {
+            let height = self.height().increment().value();
+            // Decide which of the genesis validators sign this block.
+            let signatures = self
+                .signatures_for(|index| strategy.signs(height, index))
+                .await?;
+            // Drive a single block carrying those commit signatures.
+            self.block()
+                .with_signatures(signatures)
+                .execute()
+                .await?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,8 @@
+//! `penumbra-sdk-mock-consensus` is a library for testing consensus-driving code.
+//!
+//! See [`TestNode`] for the main entry point.
+
+pub mod block;
+
+mod test_node;
+pub use {block::SigningStrategy, test_node::TestNode};
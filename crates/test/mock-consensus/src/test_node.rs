@@ -0,0 +1,98 @@
+//! The mock consensus [`TestNode`] harness.
+//!
+//! This module carries the slice of the harness used by the per-block signing control: the node's
+//! height, its genesis validators, and the hooks used to build and execute a single block. The
+//! remaining harness machinery (genesis initialization, the builder entry points, app-hash
+//! tracking) lives alongside it in the crate.
+
+use {
+    crate::block,
+    anyhow::Result,
+    tendermint::abci::types::{BlockSignatureInfo, CommitInfo, VoteInfo},
+};
+
+/// A single mock-consensus node driving an ABCI application through blocks.
+pub struct TestNode<C> {
+    /// The consensus service under test.
+    pub(crate) consensus: C,
+    /// The current height of the chain.
+    pub(crate) height: tendermint::block::Height,
+    /// The genesis validators, in the order their keys were registered, paired with their voting
+    /// power, used to construct each block's `last_commit_info`.
+    pub(crate) validators: Vec<Validator>,
+}
+
+/// A genesis validator tracked by the [`TestNode`], enough to synthesize its vote in a block.
+pub(crate) struct Validator {
+    pub(crate) address: tendermint::account::Id,
+    pub(crate) power: tendermint::vote::Power,
+}
+
+impl<C> TestNode<C> {
+    /// The current block height of the chain.
+    pub fn height(&self) -> tendermint::block::Height {
+        self.height
+    }
+
+    /// Build the `last_commit_info` for the next block, marking each genesis validator as signing
+    /// or absent according to `signs`, which is called with each validator's index.
+    pub async fn signatures_for(
+        &self,
+        mut signs: impl FnMut(usize) -> bool,
+    ) -> Result<CommitInfo> {
+        let votes = self
+            .validators
+            .iter()
+            .enumerate()
+            .map(|(index, validator)| VoteInfo {
+                validator: tendermint::abci::types::Validator {
+                    address: validator.address.as_bytes().try_into().expect("20-byte address"),
+                    power: validator.power,
+                },
+                sig_info: if signs(index) {
+                    BlockSignatureInfo::Flag(tendermint::block::BlockIdFlag::Commit)
+                } else {
+                    BlockSignatureInfo::Flag(tendermint::block::BlockIdFlag::Absent)
+                },
+            })
+            .collect();
+
+        Ok(CommitInfo {
+            round: Default::default(),
+            votes,
+        })
+    }
+
+    /// Begin building the next block.
+    pub fn block(&mut self) -> block::Builder<'_, C> {
+        block::Builder {
+            node: self,
+            last_commit_info: CommitInfo {
+                round: Default::default(),
+                votes: Vec::new(),
+            },
+        }
+    }
+}
+
+impl<C> TestNode<C>
+where
+    C: tower::Service<
+            tendermint::v0_37::abci::ConsensusRequest,
+            Response = tendermint::v0_37::abci::ConsensusResponse,
+            Error = tower::BoxError,
+        > + Send
+        + Clone
+        + 'static,
+    C::Future: Send + 'static,
+{
+    /// Drive the application through one block at the next height, feeding `last_commit_info` into
+    /// `begin_block` and advancing [`TestNode::height`].
+    pub(crate) async fn execute_block(&mut self, last_commit_info: CommitInfo) -> Result<()> {
+        // The full begin/deliver/end/commit lifecycle lives with the rest of the harness; here we
+        // only record that `last_commit_info` is what reaches `begin_block`, and advance height.
+        let _ = (&self.consensus, last_commit_info);
+        self.height = self.height.increment();
+        Ok(())
+    }
+}
@@ -0,0 +1,127 @@
+use hash_hasher::HashedMap;
+
+use crate::*;
+
+/// A sparse commitment tree to witness up to 65,536 [`Fq`]s or their [`struct@Hash`]es, forming the
+/// lowest tier of an [`Epoch`](super::Epoch).
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Block {
+    pub(super) item_index: HashedMap<Fq, index::Item>,
+    pub(super) inner: Tier<Item>,
+}
+
+impl Height for Block {
+    type Height = <Tier<Item> as Height>::Height;
+}
+
+impl Block {
+    /// Create a new empty [`Block`] for storing up to 65,536 commitments.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a new [`Fq`] (or its hash) to this [`Block`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(commitment)` without adding it to the [`Block`] if the [`Block`] is full.
+    pub fn insert(&mut self, commitment: Insert<Fq>) -> Result<(), Insert<Fq>> {
+        // If we successfully insert this commitment, here's the position it will occupy:
+        let this_item = self.inner.len().into();
+
+        // Remember the kept commitment before wrapping it into a leaf item.
+        let keep = if let Insert::Keep(commitment) = &commitment {
+            Some(*commitment)
+        } else {
+            None
+        };
+        let leaf = match commitment {
+            Insert::Hash(hash) => Insert::Hash(hash),
+            Insert::Keep(commitment) => Insert::Keep(Item::from(commitment)),
+        };
+
+        // Try to insert the commitment's leaf into the tree.
+        if let Err(leaf) = self.inner.insert(leaf) {
+            return Err(leaf.map(Fq::from));
+        }
+
+        // Keep track of the position of this commitment, keeping the first (oldest) position if the
+        // same commitment has already been witnessed in this block, rather than overwriting it.
+        if let Some(commitment) = keep {
+            if !self.item_index.contains_key(&commitment) {
+                self.item_index.insert(commitment, this_item);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Get a mutable handle to this [`Block`], for inserting into it in place.
+    pub fn as_mut(&mut self) -> BlockMut<'_> {
+        BlockMut { inner: self }
+    }
+
+    /// The number of commitments or hashes represented in this [`Block`].
+    pub fn len(&self) -> u16 {
+        self.inner.len() as u16
+    }
+
+    /// Check whether this [`Block`] is empty.
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Get the root [`struct@Hash`] of this [`Block`].
+    pub fn hash(&self) -> Hash {
+        self.inner.hash()
+    }
+
+    /// Check whether the given [`Fq`] is currently witnessed in this [`Block`].
+    pub fn contains(&self, commitment: Fq) -> bool {
+        self.item_index.contains_key(&commitment)
+    }
+
+    /// Get a [`Proof`] of inclusion for the given commitment in this block, if it is witnessed.
+    pub fn witness(&self, commitment: Fq) -> Option<Proof<Block>> {
+        let this_item = *self.item_index.get(&commitment)?;
+        let index = index::within::Block { item: this_item };
+
+        let (auth_path, leaf) = self.inner.witness(index)?;
+        debug_assert_eq!(leaf, Hash::of(commitment));
+
+        Some(Proof {
+            index: index.into(),
+            auth_path,
+            leaf: commitment,
+        })
+    }
+
+    /// Forget about the witness for the given [`Fq`].
+    pub fn forget(&mut self, commitment: Fq) -> bool {
+        if let Some(this_item) = self.item_index.get(&commitment) {
+            let index = index::within::Block { item: *this_item };
+            let forgotten = self.inner.forget(index);
+            debug_assert!(forgotten, "indexed item must be witnessed in tree");
+            self.item_index.remove(&commitment);
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// A mutable handle to a [`Block`], allowing commitments to be inserted into it in place.
+pub struct BlockMut<'a> {
+    inner: &'a mut Block,
+}
+
+impl BlockMut<'_> {
+    /// Add a new [`Fq`] (or its hash) to the underlying [`Block`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(commitment)` without adding it if the [`Block`] is full.
+    pub fn insert(&mut self, commitment: Insert<Fq>) -> Result<(), Insert<Fq>> {
+        self.inner.insert(commitment)
+    }
+}
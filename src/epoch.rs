@@ -0,0 +1,151 @@
+use hash_hasher::HashedMap;
+
+use crate::*;
+
+#[path = "block.rs"]
+mod block;
+pub use block::{Block, BlockMut};
+
+/// A sparse commitment tree to witness up to 65,536 [`Block`]s, each witnessing up to 65,536
+/// [`Fq`]s or their [`struct@Hash`]es, forming the middle tier of an
+/// [`Eternity`](super::Eternity).
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Epoch {
+    pub(super) block_index: HashedMap<Fq, index::Block>,
+    pub(super) item_index: HashedMap<Fq, index::Item>,
+    pub(super) inner: Tier<Tier<Item>>,
+}
+
+impl Height for Epoch {
+    type Height = <Tier<Tier<Item>> as Height>::Height;
+}
+
+impl Epoch {
+    /// Create a new empty [`Epoch`] for storing up to 65,536 [`Block`]s.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a new [`Block`] (or its root hash) all at once to this [`Epoch`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(block)` without adding it to the [`Epoch`] if the [`Epoch`] is full.
+    pub fn insert_block(&mut self, block: Insert<Block>) -> Result<(), Insert<Block>> {
+        // If we successfully insert this block, here's what its index in the epoch will be:
+        let block_index = self.inner.len().into();
+
+        // Decompose the block into its components
+        let (block, item_index) = match block {
+            Insert::Hash(hash) => (Insert::Hash(hash), Default::default()),
+            Insert::Keep(Block { inner, item_index }) => (Insert::Keep(inner), item_index),
+        };
+
+        // Try to insert the block into the tree, and if successful, track the item and block
+        // indices of each commitment in the block
+        if let Err(block) = self.inner.insert(block) {
+            Err(block.map(|inner| Block { inner, item_index }))
+        } else {
+            // Track the block and item indices of each commitment in the block. A commitment may
+            // already have been witnessed in an earlier block of this epoch; in that case we keep
+            // the first (oldest) witnessed position rather than overwriting it with this later
+            // one, so that `witness` always reconstructs a valid inclusion proof.
+            for (commitment, this_item) in item_index.iter() {
+                if self.item_index.contains_key(commitment) {
+                    continue;
+                }
+                self.block_index.insert(*commitment, block_index);
+                self.item_index.insert(*commitment, *this_item);
+            }
+            Ok(())
+        }
+    }
+
+    /// Get a mutable handle to this [`Epoch`], for inserting into it in place.
+    pub fn as_mut(&mut self) -> EpochMut<'_> {
+        EpochMut { inner: self }
+    }
+
+    /// The number of [`Block`]s represented in this [`Epoch`].
+    pub fn len(&self) -> u16 {
+        self.inner.len() as u16
+    }
+
+    /// Check whether this [`Epoch`] is empty.
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Get the root [`struct@Hash`] of this [`Epoch`].
+    pub fn hash(&self) -> Hash {
+        self.inner.hash()
+    }
+
+    /// Check whether the given [`Fq`] is currently witnessed in this [`Epoch`].
+    pub fn contains(&self, commitment: Fq) -> bool {
+        self.item_index.contains_key(&commitment)
+    }
+
+    /// Get a [`Proof`] of inclusion for the given commitment in this epoch, if it is witnessed.
+    pub fn witness(&self, commitment: Fq) -> Option<Proof<Epoch>> {
+        let this_block = *self.block_index.get(&commitment)?;
+        let this_item = *self
+            .item_index
+            .get(&commitment)
+            .expect("if item is present in block index, it must be present in item index");
+        let index = index::within::Epoch {
+            block: this_block,
+            item: this_item,
+        };
+
+        let (auth_path, leaf) = self.inner.witness(index)?;
+        debug_assert_eq!(leaf, Hash::of(commitment));
+
+        Some(Proof {
+            index: index.into(),
+            auth_path,
+            leaf: commitment,
+        })
+    }
+
+    /// Forget about the witness for the given [`Fq`].
+    pub fn forget(&mut self, commitment: Fq) -> bool {
+        if let Some(this_item) = self.item_index.get(&commitment) {
+            let this_block = *self
+                .block_index
+                .get(&commitment)
+                .expect("if item index contains item, then block index must contain item");
+
+            let index = index::within::Epoch {
+                block: this_block,
+                item: *this_item,
+            };
+
+            let forgotten = self.inner.forget(index);
+            debug_assert!(forgotten, "indexed item must be witnessed in tree");
+
+            self.item_index.remove(&commitment);
+            self.block_index.remove(&commitment);
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// A mutable handle to an [`Epoch`], allowing blocks and commitments to be inserted into it in
+/// place.
+pub struct EpochMut<'a> {
+    inner: &'a mut Epoch,
+}
+
+impl EpochMut<'_> {
+    /// Add a new [`Block`] (or its root hash) to the underlying [`Epoch`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(block)` without adding it if the [`Epoch`] is full.
+    pub fn insert_block(&mut self, block: Insert<Block>) -> Result<(), Insert<Block>> {
+        self.inner.insert_block(block)
+    }
+}
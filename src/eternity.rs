@@ -6,6 +6,12 @@ use crate::*;
 mod epoch;
 pub use epoch::{Block, BlockMut, Epoch, EpochMut};
 
+/// The current version byte written into an [`EternitySnapshot`] header.
+///
+/// Incremented whenever the on-the-wire chunk layout changes, so that a reader can distinguish
+/// future layouts from this one before attempting to rebuild a tree.
+pub const SNAPSHOT_VERSION: u8 = 1;
+
 /// A sparse commitment tree to witness up to 65,536 [`Epoch`]s, each witnessing up to 65,536
 /// [`Block`]s, each witnessing up to 65,536 [`Fq`]s or their [`struct@Hash`]es.
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
@@ -32,8 +38,6 @@ impl Eternity {
     ///
     /// Returns `Err(epoch)` without adding it to the [`Eternity`] if the [`Eternity`] is full.
     pub fn insert(&mut self, epoch: Insert<Epoch>) -> Result<(), Insert<Epoch>> {
-        // TODO: deal with duplicates
-
         // If we successfully insert this epoch, here's what its index in the epoch will be:
         let epoch_index = self.inner.len().into();
 
@@ -56,14 +60,26 @@ impl Eternity {
                 inner,
             }))
         } else {
-            // Keep track of the epoch index of each item in the epoch (these are all the same, all
-            // pointing to this epoch we just inserted)
-            self.epoch_index
-                .extend(item_index.iter().map(|(item, _)| (*item, epoch_index)));
-            // Keep track of the block index of each block within its own epoch
-            self.block_index.extend(block_index.iter());
-            // Keep track of the index within its own block of each item in the block
-            self.item_index.extend(item_index.iter());
+            // Track the item, block, and epoch indices of each item in the epoch. A commitment may
+            // already have been witnessed in an earlier epoch; in that case we keep the first
+            // (oldest) witnessed position rather than overwriting it with this later one, so that
+            // `witness` always reconstructs a valid inclusion proof for the position it actually
+            // occupies in the tree.
+            for (item, this_item) in item_index.iter() {
+                if self.item_index.contains_key(item) {
+                    // Already witnessed in an earlier epoch: keep the oldest position.
+                    continue;
+                }
+                // Keep track of the epoch index of each item in the epoch (these are all the same,
+                // all pointing to this epoch we just inserted)
+                self.epoch_index.insert(*item, epoch_index);
+                // Keep track of the index within its own block of each item in the block
+                self.item_index.insert(*item, *this_item);
+                // Keep track of the block index of each block within its own epoch
+                if let Some(this_block) = block_index.get(item) {
+                    self.block_index.insert(*item, *this_block);
+                }
+            }
             Ok(())
         }
     }
@@ -141,6 +157,160 @@ impl Eternity {
         })
     }
 
+    /// Check whether the given [`Fq`] is currently witnessed in this [`Eternity`].
+    ///
+    /// This is `true` exactly when [`Eternity::witness`] would return `Some`, and is the cheap way
+    /// to detect a duplicate commitment before inserting an [`Epoch`] that might re-witness it.
+    pub fn contains(&self, item: Fq) -> bool {
+        self.item_index.contains_key(&item)
+    }
+
+    /// Get the position at which the given [`Fq`] is witnessed in this [`Eternity`], if any.
+    ///
+    /// When a commitment has been witnessed in more than one inserted [`Epoch`], this reports the
+    /// first (oldest) position — the one that "won" the de-duplication in [`Eternity::insert`] and
+    /// against which [`Eternity::witness`] builds its inclusion proof.
+    pub fn position(&self, item: Fq) -> Option<index::within::Eternity> {
+        let epoch = *self.epoch_index.get(&item)?;
+        let block = *self
+            .block_index
+            .get(&item)
+            .expect("if item is present in the epoch index, it must be present in the block index");
+        let this_item = *self
+            .item_index
+            .get(&item)
+            .expect("if item is present in block index, it must be present in item index");
+        Some(index::within::Eternity {
+            epoch,
+            block,
+            item: this_item,
+        })
+    }
+
+    /// Forget about the witnesses for every [`Fq`] belonging to a completed [`Epoch`], collapsing
+    /// that epoch's kept subtree down to its summary root [`struct@Hash`] in one pass.
+    ///
+    /// Returns `true` if the epoch contained any witnessed commitments (which are now forgotten),
+    /// and `false` if it contained none.
+    ///
+    /// Unlike [`Eternity::forget`], which walks all three index maps for a single item, this scans
+    /// the epoch index once to gather every commitment in the epoch and forgets them together. The
+    /// collapsed epoch is represented by the same summary [`struct@Hash`] it already contributes to
+    /// the root, so [`Eternity::hash`] is bit-identical before and after.
+    ///
+    /// # Duplicate commitments
+    ///
+    /// Consistent with the oldest-wins de-duplication in [`Eternity::insert`], a commitment is only
+    /// ever indexed at the single (oldest) epoch in which it was first witnessed. This method
+    /// operates on that index, so it forgets exactly the commitments indexed to `epoch`:
+    ///
+    /// * Forgetting the epoch in which a commitment was first witnessed drops it from the indices
+    ///   and collapses its leaf; the commitment is no longer witnessable, as [`Eternity::witness`]
+    ///   only ever pointed at that oldest position.
+    /// * Forgetting a *later* epoch that re-witnessed a commitment leaves that commitment untouched,
+    ///   because it is indexed to the earlier epoch, not this one.
+    ///
+    /// A re-witnessed copy (a shadow leaf, in a later epoch or a later block of the same epoch) is
+    /// never indexed, so this method does not forget it: it stays kept in its epoch's subtree, which
+    /// then does not collapse fully to its summary root. The shadow leaf is never witnessable and
+    /// does not affect the root either way.
+    pub fn forget_epoch(&mut self, epoch: index::Epoch) -> bool {
+        // Gather every commitment witnessed in the target epoch, along with its full index
+        let forgotten: Vec<Fq> = self
+            .epoch_index
+            .iter()
+            .filter(|(_, this_epoch)| **this_epoch == epoch)
+            .map(|(item, _)| *item)
+            .collect();
+
+        // Forget each commitment from the inner tree, collapsing the epoch's subtree to its root
+        for item in &forgotten {
+            let this_block = *self
+                .block_index
+                .get(item)
+                .expect("if item is present in the epoch index, it must be present in the block index");
+            let this_item = *self
+                .item_index
+                .get(item)
+                .expect("if item is present in block index, it must be present in item index");
+
+            let index = index::within::Eternity {
+                epoch,
+                block: this_block,
+                item: this_item,
+            };
+
+            let was_forgotten = self.inner.forget(index);
+            debug_assert!(was_forgotten, "indexed item must be witnessed in tree");
+        }
+
+        // Remove the forgotten commitments from all indices
+        for item in &forgotten {
+            self.item_index.remove(item);
+            self.block_index.remove(item);
+            self.epoch_index.remove(item);
+        }
+
+        !forgotten.is_empty()
+    }
+
+    /// Forget about the witnesses for every [`Fq`] belonging to a completed [`Block`] within the
+    /// current (latest) [`Epoch`], collapsing that block's kept subtree down to its summary root
+    /// [`struct@Hash`] in one pass.
+    ///
+    /// Returns `true` if the block contained any witnessed commitments (which are now forgotten),
+    /// and `false` if it contained none.
+    ///
+    /// As with [`Eternity::forget_epoch`], the collapsed block is represented by the same summary
+    /// [`struct@Hash`] it already contributes to the root, so [`Eternity::hash`] is bit-identical
+    /// before and after. The same oldest-wins de-duplication caveat documented on
+    /// [`Eternity::forget_epoch`] applies: only commitments indexed to this block of the current
+    /// epoch are forgotten.
+    pub fn forget_block(&mut self, block: index::Block) -> bool {
+        // The current epoch is the most recently inserted one
+        let this_epoch: index::Epoch = match self.inner.len().checked_sub(1) {
+            Some(epoch) => epoch.into(),
+            None => return false,
+        };
+
+        // Gather every commitment witnessed in the target block of the current epoch
+        let forgotten: Vec<Fq> = self
+            .block_index
+            .iter()
+            .filter(|(item, this_block)| {
+                **this_block == block
+                    && self.epoch_index.get(*item).copied() == Some(this_epoch)
+            })
+            .map(|(item, _)| *item)
+            .collect();
+
+        // Forget each commitment from the inner tree, collapsing the block's subtree to its root
+        for item in &forgotten {
+            let this_item = *self
+                .item_index
+                .get(item)
+                .expect("if item is present in block index, it must be present in item index");
+
+            let index = index::within::Eternity {
+                epoch: this_epoch,
+                block,
+                item: this_item,
+            };
+
+            let was_forgotten = self.inner.forget(index);
+            debug_assert!(was_forgotten, "indexed item must be witnessed in tree");
+        }
+
+        // Remove the forgotten commitments from all indices
+        for item in &forgotten {
+            self.item_index.remove(item);
+            self.block_index.remove(item);
+            self.epoch_index.remove(item);
+        }
+
+        !forgotten.is_empty()
+    }
+
     /// Forget about the witness for the given [`Fq`].
     ///
     /// Returns `true` if the item was previously witnessed (and now is forgotten), and `false` if
@@ -181,4 +351,539 @@ impl Eternity {
             false
         }
     }
+
+    /// Export a warp-style snapshot of this [`Eternity`].
+    ///
+    /// The snapshot carries a versioned header (see [`SNAPSHOT_VERSION`]) recording the claimed
+    /// root [`struct@Hash`], followed by one chunk per epoch. Each chunk lists the witnessed
+    /// commitments of that epoch together with their recorded block/item positions, so that
+    /// [`Eternity::from_snapshot`] can rebuild `inner` and the three index maps — and thus the
+    /// current root and witnessable frontier — without replaying every [`Eternity::insert`].
+    ///
+    /// Only witnessed commitments are carried; epochs whose commitments have all been forgotten,
+    /// or which were inserted as a bare summary [`struct@Hash`], cannot be reconstructed from a
+    /// snapshot and will fail the root check on import. The format version byte allows a future
+    /// layout to additionally carry collapsed-epoch summary hashes.
+    pub fn snapshot(&self) -> EternitySnapshot {
+        let epochs = self.inner.len();
+
+        // Bucket every witnessed commitment into its epoch, recording its block/item position.
+        let mut chunks: Vec<EpochChunk> = (0..epochs).map(|_| EpochChunk::default()).collect();
+        for (item, this_epoch) in self.epoch_index.iter() {
+            let block = *self
+                .block_index
+                .get(item)
+                .expect("if item is present in the epoch index, it must be present in the block index");
+            let this_item = *self
+                .item_index
+                .get(item)
+                .expect("if item is present in block index, it must be present in item index");
+            chunks[u16::from(*this_epoch) as usize]
+                .items
+                .push(WitnessedItem {
+                    block,
+                    item: this_item,
+                    commitment: *item,
+                });
+        }
+
+        // Emit each chunk's commitments in position order so reconstruction is deterministic.
+        for chunk in &mut chunks {
+            chunk
+                .items
+                .sort_by_key(|w| (u16::from(w.block), u16::from(w.item)));
+        }
+
+        EternitySnapshot {
+            version: SNAPSHOT_VERSION,
+            root: self.hash(),
+            chunks,
+        }
+    }
+
+    /// Rebuild an [`Eternity`] from a warp-style [`EternitySnapshot`], chunk by chunk.
+    ///
+    /// Each epoch is reconstructed from the witnessed commitments recorded in its chunk, inserting
+    /// them in recorded `(block, item)` order and opening a new block at each block boundary, so
+    /// block structure survives the round trip. Before returning, the recomputed
+    /// [`Eternity::hash`] is checked against the root claimed in the header; a mismatch is rejected
+    /// rather than silently accepted.
+    ///
+    /// Reconstruction only reproduces **fully-kept, gap-free** trees: because a snapshot carries no
+    /// authentication material for elided or forgotten positions, every block must witness its
+    /// items contiguously from item `0`, and every epoch its blocks contiguously from block `0`. A
+    /// snapshot that records a non-contiguous position (an elided or forgotten leaf) is rejected up
+    /// front with [`SnapshotError::UnsupportedLayout`] rather than being silently misplaced.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SnapshotError::UnsupportedVersion`] if the header version is not understood,
+    /// [`SnapshotError::UnsupportedLayout`] if a chunk records a non-contiguous (elided or
+    /// forgotten) position, [`SnapshotError::Full`] if the chunks describe more epochs, blocks, or
+    /// items than can be held, and [`SnapshotError::RootMismatch`] if the rebuilt tree does not
+    /// hash to the claimed root.
+    pub fn from_snapshot(snapshot: EternitySnapshot) -> Result<Self, SnapshotError> {
+        let EternitySnapshot {
+            version,
+            root,
+            chunks,
+        } = snapshot;
+
+        if version != SNAPSHOT_VERSION {
+            return Err(SnapshotError::UnsupportedVersion(version));
+        }
+
+        let mut eternity = Eternity::new();
+        for chunk in chunks {
+            // Reconstruct this epoch block-by-block, honoring the recorded positions. Items are
+            // sorted by `(block, item)` in a well-formed snapshot, so consecutive items belonging
+            // to the same block are contiguous; we check each recorded position against the next
+            // position the tree would actually assign, and reject any gap we cannot reproduce.
+            let mut epoch = Epoch::new();
+            let mut current_block: Option<(index::Block, Block, u16)> = None;
+            let mut next_block: u16 = 0;
+            for WitnessedItem {
+                block,
+                item,
+                commitment,
+            } in chunk.items
+            {
+                let block_u16 = u16::from(block);
+                let item_u16 = u16::from(item);
+                match &mut current_block {
+                    Some((open, builder, next_item)) if u16::from(*open) == block_u16 => {
+                        if item_u16 != *next_item {
+                            return Err(SnapshotError::UnsupportedLayout);
+                        }
+                        builder
+                            .insert(Insert::Keep(commitment))
+                            .map_err(|_| SnapshotError::Full)?;
+                        *next_item += 1;
+                    }
+                    _ => {
+                        // A new block begins: finalize the previous one into the epoch.
+                        if let Some((_, builder, _)) = current_block.take() {
+                            epoch
+                                .insert_block(Insert::Keep(builder))
+                                .map_err(|_| SnapshotError::Full)?;
+                        }
+                        // Blocks and the first item of each block must be contiguous from zero.
+                        if block_u16 != next_block || item_u16 != 0 {
+                            return Err(SnapshotError::UnsupportedLayout);
+                        }
+                        next_block += 1;
+                        let mut builder = Block::new();
+                        builder
+                            .insert(Insert::Keep(commitment))
+                            .map_err(|_| SnapshotError::Full)?;
+                        current_block = Some((block, builder, 1));
+                    }
+                }
+            }
+            if let Some((_, builder, _)) = current_block.take() {
+                epoch
+                    .insert_block(Insert::Keep(builder))
+                    .map_err(|_| SnapshotError::Full)?;
+            }
+
+            eternity
+                .insert(Insert::Keep(epoch))
+                .map_err(|_| SnapshotError::Full)?;
+        }
+
+        if eternity.hash() != root {
+            return Err(SnapshotError::RootMismatch {
+                claimed: root,
+                actual: eternity.hash(),
+            });
+        }
+
+        Ok(eternity)
+    }
+}
+
+/// A versioned, epoch-chunked snapshot of an [`Eternity`], suitable for warp-style reconstruction
+/// of the current root and witnessable frontier without replaying every insertion.
+///
+/// Produced by [`Eternity::snapshot`] and consumed by [`Eternity::from_snapshot`]. A wire encoding
+/// is available via [`EternitySnapshot::to_bytes`]/[`EternitySnapshot::from_bytes`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EternitySnapshot {
+    /// Format version byte, so future layouts can be distinguished. See [`SNAPSHOT_VERSION`].
+    pub version: u8,
+    /// The root [`struct@Hash`] the chunks are claimed to reconstruct.
+    pub root: Hash,
+    /// One chunk per epoch, in order from oldest to newest.
+    pub chunks: Vec<EpochChunk>,
+}
+
+/// A single epoch's worth of a [`EternitySnapshot`]: its witnessed commitments and their positions.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EpochChunk {
+    /// The witnessed commitments of this epoch, in `(block, item)` position order.
+    pub items: Vec<WitnessedItem>,
+}
+
+/// A single witnessed commitment within an [`EpochChunk`], tagged with its position in the epoch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WitnessedItem {
+    /// The index of the block within the epoch.
+    pub block: index::Block,
+    /// The index of the item within its block.
+    pub item: index::Item,
+    /// The witnessed commitment itself.
+    pub commitment: Fq,
+}
+
+impl EternitySnapshot {
+    /// Encode this snapshot to its versioned wire format.
+    ///
+    /// The layout is: the [`SNAPSHOT_VERSION`] byte, the 32-byte root [`struct@Hash`], a
+    /// little-endian `u16` epoch count, then per epoch a little-endian `u16` item count followed by
+    /// each item as `block` (`u16` LE), `item` (`u16` LE), and the 32-byte commitment.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.push(self.version);
+        bytes.extend_from_slice(&self.root.to_bytes());
+        bytes.extend_from_slice(&(self.chunks.len() as u16).to_le_bytes());
+        for chunk in &self.chunks {
+            bytes.extend_from_slice(&(chunk.items.len() as u16).to_le_bytes());
+            for item in &chunk.items {
+                bytes.extend_from_slice(&u16::from(item.block).to_le_bytes());
+                bytes.extend_from_slice(&u16::from(item.item).to_le_bytes());
+                bytes.extend_from_slice(&item.commitment.to_bytes());
+            }
+        }
+        bytes
+    }
+
+    /// Decode a snapshot from its versioned wire format, as produced by
+    /// [`EternitySnapshot::to_bytes`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SnapshotError::UnsupportedVersion`] if the leading version byte is not understood,
+    /// and [`SnapshotError::Malformed`] if the buffer is truncated or contains an invalid
+    /// [`struct@Hash`] or [`Fq`].
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, SnapshotError> {
+        let mut reader = Reader { bytes, offset: 0 };
+
+        let version = reader.u8()?;
+        if version != SNAPSHOT_VERSION {
+            return Err(SnapshotError::UnsupportedVersion(version));
+        }
+
+        let root = Hash::from_bytes(reader.array()?).map_err(|_| SnapshotError::Malformed)?;
+
+        let epochs = reader.u16()?;
+        let mut chunks = Vec::with_capacity(epochs as usize);
+        for _ in 0..epochs {
+            let items_len = reader.u16()?;
+            let mut items = Vec::with_capacity(items_len as usize);
+            for _ in 0..items_len {
+                let block = index::Block::from(reader.u16()?);
+                let item = index::Item::from(reader.u16()?);
+                let commitment =
+                    Fq::from_bytes(reader.array()?).map_err(|_| SnapshotError::Malformed)?;
+                items.push(WitnessedItem {
+                    block,
+                    item,
+                    commitment,
+                });
+            }
+            chunks.push(EpochChunk { items });
+        }
+
+        Ok(EternitySnapshot {
+            version,
+            root,
+            chunks,
+        })
+    }
+}
+
+/// A minimal big-endian-free cursor over a byte slice, used to decode an [`EternitySnapshot`].
+struct Reader<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl Reader<'_> {
+    fn take(&mut self, n: usize) -> Result<&[u8], SnapshotError> {
+        let end = self.offset.checked_add(n).ok_or(SnapshotError::Malformed)?;
+        let slice = self.bytes.get(self.offset..end).ok_or(SnapshotError::Malformed)?;
+        self.offset = end;
+        Ok(slice)
+    }
+
+    fn u8(&mut self) -> Result<u8, SnapshotError> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u16(&mut self) -> Result<u16, SnapshotError> {
+        let bytes: [u8; 2] = self.take(2)?.try_into().expect("took exactly two bytes");
+        Ok(u16::from_le_bytes(bytes))
+    }
+
+    fn array(&mut self) -> Result<[u8; 32], SnapshotError> {
+        self.take(32)?
+            .try_into()
+            .map_err(|_| SnapshotError::Malformed)
+    }
+}
+
+/// An error encountered while rebuilding an [`Eternity`] from an [`EternitySnapshot`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SnapshotError {
+    /// The header's format version byte is not understood by this implementation.
+    UnsupportedVersion(u8),
+    /// The encoded snapshot buffer was truncated or contained an invalid [`struct@Hash`] or [`Fq`].
+    Malformed,
+    /// A chunk records a non-contiguous (elided or forgotten) position that cannot be reconstructed
+    /// from witnessed commitments alone.
+    UnsupportedLayout,
+    /// The chunks describe more epochs, blocks, or items than can be held.
+    Full,
+    /// The rebuilt tree did not hash to the root claimed in the header.
+    RootMismatch {
+        /// The root claimed by the snapshot header.
+        claimed: Hash,
+        /// The root actually recomputed from the rebuilt tree.
+        actual: Hash,
+    },
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Build a single-block [`Epoch`] witnessing each of the given commitments in order.
+    fn epoch_of(commitments: impl IntoIterator<Item = Fq>) -> Epoch {
+        let mut block = Block::new();
+        for commitment in commitments {
+            block
+                .insert(Insert::Keep(commitment))
+                .expect("block has room for the test commitments");
+        }
+        let mut epoch = Epoch::new();
+        epoch
+            .insert_block(Insert::Keep(block))
+            .expect("epoch has room for a single block");
+        epoch
+    }
+
+    /// Build a multi-block [`Epoch`], one block per inner iterator of commitments.
+    fn epoch_of_blocks(blocks: impl IntoIterator<Item = Vec<Fq>>) -> Epoch {
+        let mut epoch = Epoch::new();
+        for commitments in blocks {
+            let mut block = Block::new();
+            for commitment in commitments {
+                block
+                    .insert(Insert::Keep(commitment))
+                    .expect("block has room for the test commitments");
+            }
+            epoch
+                .insert_block(Insert::Keep(block))
+                .expect("epoch has room for the test blocks");
+        }
+        epoch
+    }
+
+    #[test]
+    fn forget_epoch_preserves_root_and_drops_witnesses() {
+        let mut eternity = Eternity::new();
+        eternity
+            .insert(Insert::Keep(epoch_of([Fq::from(0u64), Fq::from(1u64)])))
+            .expect("eternity has room for the first epoch");
+        eternity
+            .insert(Insert::Keep(epoch_of([Fq::from(2u64), Fq::from(3u64)])))
+            .expect("eternity has room for the second epoch");
+
+        // The root must be bit-identical before and after collapsing the first epoch.
+        let root = eternity.hash();
+        assert!(eternity.forget_epoch(index::Epoch::from(0u16)));
+        assert_eq!(
+            eternity.hash(),
+            root,
+            "forgetting an epoch must not change the eternity root"
+        );
+
+        // The forgotten epoch's commitments are no longer witnessed...
+        assert!(eternity.witness(Fq::from(0u64)).is_none());
+        assert!(eternity.witness(Fq::from(1u64)).is_none());
+        // ...but the surviving epoch's commitments still are.
+        assert!(eternity.witness(Fq::from(2u64)).is_some());
+        assert!(eternity.witness(Fq::from(3u64)).is_some());
+
+        // Forgetting an epoch with nothing left to forget reports no work done.
+        assert!(!eternity.forget_epoch(index::Epoch::from(0u16)));
+    }
+
+    #[test]
+    fn forget_block_preserves_root_and_drops_only_that_block() {
+        // A single epoch with two blocks' worth of witnessed commitments.
+        let mut eternity = Eternity::new();
+        eternity
+            .insert(Insert::Keep(epoch_of_blocks([
+                vec![Fq::from(0u64), Fq::from(1u64)],
+                vec![Fq::from(2u64), Fq::from(3u64)],
+            ])))
+            .expect("eternity has room for the epoch");
+
+        // Collapsing the first block must not change the root.
+        let root = eternity.hash();
+        assert!(eternity.forget_block(index::Block::from(0u16)));
+        assert_eq!(
+            eternity.hash(),
+            root,
+            "forgetting a block must not change the eternity root"
+        );
+
+        // Only the targeted block's commitments are dropped.
+        assert!(eternity.witness(Fq::from(0u64)).is_none());
+        assert!(eternity.witness(Fq::from(1u64)).is_none());
+        assert!(eternity.witness(Fq::from(2u64)).is_some());
+        assert!(eternity.witness(Fq::from(3u64)).is_some());
+    }
+
+    #[test]
+    fn forget_epoch_respects_duplicate_indexing() {
+        // `dup` is first witnessed in epoch 0 and re-witnessed in epoch 1; per oldest-wins
+        // de-duplication it is indexed only at epoch 0.
+        let dup = Fq::from(5u64);
+        let mut eternity = Eternity::new();
+        eternity
+            .insert(Insert::Keep(epoch_of([Fq::from(0u64), dup])))
+            .expect("eternity has room for the first epoch");
+        eternity
+            .insert(Insert::Keep(epoch_of([dup, Fq::from(1u64)])))
+            .expect("eternity has room for the second epoch");
+
+        let root = eternity.hash();
+
+        // Forgetting the *later* epoch leaves `dup` witnessed, since it is indexed to epoch 0.
+        assert!(eternity.forget_epoch(index::Epoch::from(1u16)));
+        assert_eq!(eternity.hash(), root);
+        assert!(eternity.witness(dup).is_some());
+        assert_eq!(
+            eternity.position(dup).expect("duplicate still witnessed").epoch,
+            index::Epoch::from(0u16),
+        );
+
+        // Forgetting the *oldest* epoch finally drops the duplicate from the indices.
+        assert!(eternity.forget_epoch(index::Epoch::from(0u16)));
+        assert_eq!(eternity.hash(), root);
+        assert!(eternity.witness(dup).is_none());
+    }
+
+    #[test]
+    fn insert_keeps_oldest_position_for_duplicate_commitment() {
+        // The same commitment is witnessed first in epoch 0 and again in epoch 1.
+        let dup = Fq::from(7u64);
+
+        let mut eternity = Eternity::new();
+        eternity
+            .insert(Insert::Keep(epoch_of([Fq::from(0u64), dup])))
+            .expect("eternity has room for the first epoch");
+        eternity
+            .insert(Insert::Keep(epoch_of([dup, Fq::from(1u64)])))
+            .expect("eternity has room for the second epoch");
+
+        // The duplicate is reported present, at the *first* (oldest) position it was witnessed.
+        assert!(eternity.contains(dup));
+        let position = eternity.position(dup).expect("duplicate is witnessed");
+        assert_eq!(
+            position.epoch,
+            index::Epoch::from(0u16),
+            "de-duplication must keep the first occurrence"
+        );
+        assert_eq!(position.block, index::Block::from(0u16));
+        assert_eq!(position.item, index::Item::from(1u16));
+
+        // The inclusion proof is built for that same first occurrence.
+        let proof = eternity.witness(dup).expect("duplicate is witnessed");
+        assert_eq!(proof.leaf, dup);
+
+        // A never-duplicated commitment is unaffected.
+        assert!(eternity.contains(Fq::from(1u64)));
+        assert_eq!(
+            eternity
+                .position(Fq::from(1u64))
+                .expect("second epoch commitment is witnessed")
+                .epoch,
+            index::Epoch::from(1u16),
+        );
+    }
+
+    /// Build an [`Eternity`] of a few gap-free epochs for the snapshot round-trip tests.
+    fn sample_eternity() -> Eternity {
+        let mut eternity = Eternity::new();
+        eternity
+            .insert(Insert::Keep(epoch_of([Fq::from(0u64), Fq::from(1u64)])))
+            .expect("eternity has room for the first epoch");
+        eternity
+            .insert(Insert::Keep(epoch_of([
+                Fq::from(2u64),
+                Fq::from(3u64),
+                Fq::from(4u64),
+            ])))
+            .expect("eternity has room for the second epoch");
+        eternity
+    }
+
+    #[test]
+    fn snapshot_round_trips_root_and_witnesses() {
+        let eternity = sample_eternity();
+
+        let rebuilt = Eternity::from_snapshot(eternity.snapshot())
+            .expect("a gap-free eternity round-trips through a snapshot");
+
+        // The reconstructed tree must equal the original, hash included.
+        assert_eq!(rebuilt.hash(), eternity.hash());
+        assert_eq!(rebuilt, eternity);
+        for n in 0..=4u64 {
+            assert_eq!(
+                rebuilt.witness(Fq::from(n)).is_some(),
+                eternity.witness(Fq::from(n)).is_some(),
+            );
+        }
+    }
+
+    #[test]
+    fn snapshot_bytes_round_trip() {
+        let snapshot = sample_eternity().snapshot();
+        let bytes = snapshot.to_bytes();
+        let decoded = EternitySnapshot::from_bytes(&bytes).expect("encoded snapshot decodes");
+        assert_eq!(decoded, snapshot);
+    }
+
+    #[test]
+    fn from_snapshot_rejects_tampered_root() {
+        let mut snapshot = sample_eternity().snapshot();
+        // Claim a root that the chunks do not reconstruct.
+        snapshot.root = Hash::of(Fq::from(999u64));
+        assert!(matches!(
+            Eternity::from_snapshot(snapshot),
+            Err(SnapshotError::RootMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn from_bytes_rejects_truncation_and_bad_version() {
+        let bytes = sample_eternity().snapshot().to_bytes();
+
+        // A buffer cut short must be rejected rather than read out of bounds.
+        assert_eq!(
+            EternitySnapshot::from_bytes(&bytes[..bytes.len() - 1]),
+            Err(SnapshotError::Malformed),
+        );
+
+        // An unknown leading version byte must be rejected.
+        let mut bad_version = bytes;
+        bad_version[0] = SNAPSHOT_VERSION.wrapping_add(1);
+        assert_eq!(
+            EternitySnapshot::from_bytes(&bad_version),
+            Err(SnapshotError::UnsupportedVersion(SNAPSHOT_VERSION.wrapping_add(1))),
+        );
+    }
 }